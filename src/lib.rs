@@ -5,6 +5,8 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 
@@ -74,8 +76,200 @@ print("calcsize_pointer", struct.calcsize("P"))
     })
 }
 
+/// Build an `InterpreterConfig` for a cross-compilation target without
+/// executing the target interpreter.
+///
+/// This reads the target triple from the `CARGO_CFG_TARGET_OS`,
+/// `CARGO_CFG_TARGET_ARCH` and `CARGO_CFG_TARGET_POINTER_WIDTH` variables
+/// that Cargo sets for build scripts, the Python version to assume from
+/// `PYTHON_CROSS_VERSION` (e.g. `3.10`), and locates the target's
+/// `_sysconfigdata_*.py` file inside the directory named by
+/// `PYTHON_CROSS_LIB`. The returned config has `executable` left empty,
+/// since no interpreter for the target was ever run.
+pub fn cross_compile_config() -> Result<InterpreterConfig> {
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS")
+        .map_err(|_| "CARGO_CFG_TARGET_OS must be set to cross-compile")?;
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH")
+        .map_err(|_| "CARGO_CFG_TARGET_ARCH must be set to cross-compile")?;
+    let pointer_width: u32 = std::env::var("CARGO_CFG_TARGET_POINTER_WIDTH")
+        .map_err(|_| "CARGO_CFG_TARGET_POINTER_WIDTH must be set to cross-compile")?
+        .parse()?;
+    let version = std::env::var("PYTHON_CROSS_VERSION")
+        .map_err(|_| "PYTHON_CROSS_VERSION must be set to cross-compile, e.g. \"3.10\"")?;
+    let lib_dir = std::env::var("PYTHON_CROSS_LIB")
+        .map_err(|_| "PYTHON_CROSS_LIB must be set to cross-compile")?;
+
+    let (major, minor) = version
+        .split_once('.')
+        .ok_or("PYTHON_CROSS_VERSION must be in the form major.minor, e.g. \"3.10\"")?;
+    let major: u8 = major.parse()?;
+    let minor: u8 = minor.parse()?;
+
+    let sysconfigdata_path = find_sysconfigdata(Path::new(&lib_dir), &target_os, &target_arch)?;
+    let contents = std::fs::read_to_string(&sysconfigdata_path)?;
+    let vars = parse_build_time_vars(&contents)?;
+
+    let ld_version = vars
+        .get("LDVERSION")
+        .or_else(|| vars.get("py_version_short"))
+        .cloned()
+        .unwrap_or_else(|| format!("{}.{}", major, minor));
+    let shared = vars
+        .get("Py_ENABLE_SHARED")
+        .map(|value| value != "0")
+        .unwrap_or(false);
+    let base_prefix = vars.get("prefix").cloned().unwrap_or_default();
+
+    Ok(InterpreterConfig {
+        version: PythonVersion {
+            major,
+            minor,
+            implementation: PythonImplementation::CPython,
+        },
+        libdir: vars.get("LIBDIR").cloned(),
+        shared,
+        ld_version,
+        base_prefix,
+        executable: PathBuf::new(),
+        calcsize_pointer: pointer_width / 8,
+    })
+}
+
+/// Locate the `_sysconfigdata_*.py` file for the given target inside
+/// `sysconfig_dir`, preferring a name that mentions both the target OS and
+/// arch if more than one candidate is present.
+fn find_sysconfigdata(sysconfig_dir: &Path, target_os: &str, target_arch: &str) -> Result<PathBuf> {
+    let mut candidates = Vec::new();
+    for entry in std::fs::read_dir(sysconfig_dir)? {
+        let path = entry?.path();
+        let file_name = match path.file_name().map(|name| name.to_string_lossy()) {
+            Some(file_name) => file_name.into_owned(),
+            None => continue,
+        };
+        if file_name.starts_with("_sysconfigdata_") && file_name.ends_with(".py") {
+            candidates.push(path);
+        }
+    }
+
+    if let Some(best) = candidates.iter().find(|path| {
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        file_name.contains(target_arch) && file_name.contains(target_os)
+    }) {
+        return Ok(best.clone());
+    }
+
+    candidates.into_iter().next().ok_or_else(|| {
+        format!(
+            "no _sysconfigdata_*.py found in {}",
+            sysconfig_dir.display()
+        )
+        .into()
+    })
+}
+
+/// Extract the `build_time_vars` dict from the contents of a
+/// `_sysconfigdata_*.py` file.
+///
+/// The dict is valid Python-literal text of the form `'KEY': value, ...`, so
+/// rather than embedding a Python parser we just scan for the keys we need.
+fn parse_build_time_vars(contents: &str) -> Result<HashMap<String, String>> {
+    let dict_start = contents
+        .find("build_time_vars = {")
+        .ok_or("could not find build_time_vars in sysconfigdata")?;
+    let dict = &contents[dict_start..];
+
+    let mut vars = HashMap::new();
+    for key in ["LIBDIR", "LDVERSION", "py_version_short", "Py_ENABLE_SHARED", "prefix"] {
+        if let Some(value) = extract_dict_value(dict, key) {
+            vars.insert(key.to_string(), value);
+        }
+    }
+    Ok(vars)
+}
+
+/// Extract the value following `'key':` in a Python dict literal, handling
+/// both quoted string values and bare (e.g. integer) values.
+fn extract_dict_value(dict: &str, key: &str) -> Option<String> {
+    let needle = format!("'{}':", key);
+    let after = dict[dict.find(&needle)? + needle.len()..].trim_start();
+    if let Some(rest) = after.strip_prefix('\'') {
+        let end = rest.find('\'')?;
+        Some(rest[..end].to_string())
+    } else {
+        // No trailing comma after the last entry in the dict is valid Python,
+        // so the value may instead be terminated by the closing `}`.
+        let end = after.find([',', '\n', '}'])?;
+        Some(after[..end].trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_dict_value_quoted_string() {
+        let dict = "build_time_vars = { 'LIBDIR': '/usr/lib/python3.10', 'prefix': '/usr' }";
+        assert_eq!(
+            extract_dict_value(dict, "LIBDIR"),
+            Some("/usr/lib/python3.10".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_dict_value_bare_int() {
+        let dict = "build_time_vars = { 'Py_ENABLE_SHARED': 1, 'LIBDIR': '/usr/lib' }";
+        assert_eq!(
+            extract_dict_value(dict, "Py_ENABLE_SHARED"),
+            Some("1".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_dict_value_trailing_comma() {
+        let dict = "build_time_vars = { 'Py_ENABLE_SHARED': 0,\n}";
+        assert_eq!(
+            extract_dict_value(dict, "Py_ENABLE_SHARED"),
+            Some("0".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_dict_value_no_trailing_comma() {
+        let dict = "build_time_vars = { 'LDVERSION': '3.10', 'Py_ENABLE_SHARED': 0}";
+        assert_eq!(
+            extract_dict_value(dict, "Py_ENABLE_SHARED"),
+            Some("0".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_dict_value_missing_key() {
+        let dict = "build_time_vars = { 'LIBDIR': '/usr/lib' }";
+        assert_eq!(extract_dict_value(dict, "LDVERSION"), None);
+    }
+
+    #[test]
+    fn parse_build_time_vars_collects_known_keys() {
+        let contents = "\
+# comment before the dict
+build_time_vars = { 'LIBDIR': '/usr/lib/python3.10', 'LDVERSION': '3.10', 'Py_ENABLE_SHARED': 1}
+";
+        let vars = parse_build_time_vars(contents).unwrap();
+        assert_eq!(vars.get("LIBDIR").map(String::as_str), Some("/usr/lib/python3.10"));
+        assert_eq!(vars.get("LDVERSION").map(String::as_str), Some("3.10"));
+        assert_eq!(vars.get("Py_ENABLE_SHARED").map(String::as_str), Some("1"));
+        assert!(!vars.contains_key("prefix"));
+    }
+
+    #[test]
+    fn parse_build_time_vars_missing_dict_errors() {
+        assert!(parse_build_time_vars("no dict here").is_err());
+    }
+}
+
 /// Information about a Python interpreter
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InterpreterConfig {
     pub version: PythonVersion,
     pub libdir: Option<String>,
@@ -87,23 +281,520 @@ pub struct InterpreterConfig {
     pub calcsize_pointer: u32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+impl InterpreterConfig {
+    /// Write this config to `writer` using a simple `key=value` text format,
+    /// one setting per line.
+    ///
+    /// The result can be read back with [`InterpreterConfig::from_reader`].
+    /// This lets a build script cache a previously-probed config to disk
+    /// instead of spawning the interpreter on every build.
+    pub fn to_writer<W: io::Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "version_major={}", self.version.major)?;
+        writeln!(writer, "version_minor={}", self.version.minor)?;
+        writeln!(writer, "implementation={}", self.version.implementation)?;
+        if let Some(libdir) = &self.libdir {
+            writeln!(writer, "libdir={}", libdir)?;
+        }
+        writeln!(writer, "shared={}", self.shared)?;
+        writeln!(writer, "ld_version={}", self.ld_version)?;
+        writeln!(writer, "base_prefix={}", self.base_prefix)?;
+        writeln!(writer, "executable={}", self.executable.display())?;
+        writeln!(writer, "calcsize_pointer={}", self.calcsize_pointer)?;
+        Ok(())
+    }
+
+    /// Parse a config previously written by [`InterpreterConfig::to_writer`].
+    pub fn from_reader<R: io::Read>(mut reader: R) -> Result<Self> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+
+        let map: HashMap<String, String> = contents
+            .lines()
+            .filter_map(|line| {
+                let mut i = line.splitn(2, '=');
+                Some((i.next()?.into(), i.next()?.into()))
+            })
+            .collect();
+
+        macro_rules! get {
+            ($key:literal) => {
+                map.get($key)
+                    .ok_or_else(|| format!("Missing key {} in config", $key))
+            }
+        }
+
+        Ok(InterpreterConfig {
+            version: PythonVersion {
+                major: get!("version_major")?.parse()?,
+                minor: get!("version_minor")?.parse()?,
+                implementation: get!("implementation")?.parse()?,
+            },
+            libdir: map.get("libdir").cloned(),
+            shared: get!("shared")?.parse()?,
+            ld_version: get!("ld_version")?.clone(),
+            base_prefix: get!("base_prefix")?.clone(),
+            executable: get!("executable")?.clone().into(),
+            calcsize_pointer: get!("calcsize_pointer")?.parse()?,
+        })
+    }
+
+    /// Load a config from a file previously written by
+    /// [`InterpreterConfig::to_writer`].
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Self::from_reader(file)
+    }
+
+    /// Load a config for use from a build script.
+    ///
+    /// If `PYTHON_CONFIG_FILE` is set, the config is loaded from that file via
+    /// [`InterpreterConfig::from_config_file`] — this lets a build script
+    /// reuse a config cached by a previous run instead of spawning the
+    /// interpreter again. Otherwise, falls back to the first interpreter
+    /// found by [`find_interpreters`].
+    pub fn from_env() -> Result<Self> {
+        if let Ok(path) = std::env::var("PYTHON_CONFIG_FILE") {
+            return Self::from_config_file(path);
+        }
+        find_interpreters()
+            .next()
+            .ok_or_else(|| "No Python interpreter found".into())
+    }
+
+    /// Compute the name of the libpython library to link against (without
+    /// the `lib` prefix or platform-specific extension).
+    ///
+    /// This reads the target platform from `CARGO_CFG_TARGET_OS`, falling
+    /// back to the host platform if unset, since this is primarily intended
+    /// for use from a build script.
+    pub fn libpython_link_name(&self) -> String {
+        let target_os = std::env::var("CARGO_CFG_TARGET_OS")
+            .unwrap_or_else(|_| std::env::consts::OS.to_string());
+        self.libpython_link_name_for(&target_os)
+    }
+
+    /// The `target_os`-taking core of [`InterpreterConfig::libpython_link_name`],
+    /// split out so it can be tested without touching the environment.
+    fn libpython_link_name_for(&self, target_os: &str) -> String {
+        if self.is_pypy() {
+            "pypy3-c".to_string()
+        } else if target_os == "windows" {
+            format!("python{}{}", self.version.major, self.version.minor)
+        } else {
+            format!("python{}", self.ld_version)
+        }
+    }
+
+    /// Compute the `(search_dir, link_lib)` pair that
+    /// [`InterpreterConfig::emit_cargo_link_directives`] would print, or
+    /// `None` if `shared` is `false` and there is nothing to link against.
+    ///
+    /// Split out from `emit_cargo_link_directives` so the logic can be
+    /// tested without capturing stdout.
+    fn cargo_link_directives(&self) -> Option<(Option<String>, String)> {
+        if !self.shared {
+            return None;
+        }
+        Some((self.libdir.clone(), self.libpython_link_name()))
+    }
+
+    /// Emit the `cargo:rustc-link-search` and `cargo:rustc-link-lib`
+    /// directives needed to link against this interpreter's libpython, for
+    /// use from a build script.
+    ///
+    /// If `shared` is `false`, libpython is statically embedded in the
+    /// interpreter executable rather than built as a separate shared
+    /// library, so there is nothing to emit a `rustc-link-lib` directive
+    /// for; this is a no-op in that case.
+    pub fn emit_cargo_link_directives(&self) {
+        if let Some((libdir, link_name)) = self.cargo_link_directives() {
+            if let Some(libdir) = libdir {
+                println!("cargo:rustc-link-search=native={}", libdir);
+            }
+            println!("cargo:rustc-link-lib={}", link_name);
+        }
+    }
+
+    /// Compute the libpython link name to use when linking against CPython's
+    /// abi3 (limited/stable ABI) instead of this specific interpreter.
+    ///
+    /// Returns an error if this interpreter isn't CPython, if its minor
+    /// version is older than `abi3.min_minor`, or if `abi3.min_minor` is
+    /// newer than [`ABI3_MAX_MINOR`].
+    pub fn abi3_link_name(&self, abi3: Abi3) -> Result<String> {
+        if self.version.implementation != PythonImplementation::CPython {
+            return Err(format!(
+                "abi3 linking is only supported for CPython, not {:?}",
+                &self.version.implementation
+            )
+            .into());
+        }
+        if abi3.min_minor > ABI3_MAX_MINOR {
+            return Err(format!(
+                "abi3 min_minor {} is newer than the maximum supported minor version {}",
+                abi3.min_minor, ABI3_MAX_MINOR
+            )
+            .into());
+        }
+        if self.version.minor < abi3.min_minor {
+            return Err(format!(
+                "interpreter 3.{} is older than the requested abi3 minimum of 3.{}",
+                self.version.minor, abi3.min_minor
+            )
+            .into());
+        }
+
+        // The stable ABI name is the same on Unix (`libpython3.so`) and
+        // Windows (`python3.dll`).
+        Ok("python3".to_string())
+    }
+
+    /// Returns `true` if this interpreter is PyPy.
+    pub fn is_pypy(&self) -> bool {
+        self.version.implementation == PythonImplementation::PyPy
+    }
+
+    /// Returns `true` if this interpreter is GraalPy.
+    pub fn is_graalpy(&self) -> bool {
+        self.version.implementation == PythonImplementation::GraalPy
+    }
+}
+
+#[cfg(test)]
+fn test_config(
+    implementation: PythonImplementation,
+    minor: u8,
+    shared: bool,
+    ld_version: &str,
+) -> InterpreterConfig {
+    InterpreterConfig {
+        version: PythonVersion {
+            major: 3,
+            minor,
+            implementation,
+        },
+        libdir: Some("/usr/lib".to_string()),
+        shared,
+        ld_version: ld_version.to_string(),
+        base_prefix: "/usr".to_string(),
+        executable: PathBuf::from("/usr/bin/python3"),
+        calcsize_pointer: 8,
+    }
+}
+
+#[cfg(test)]
+mod config_roundtrip_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_cpython_config() {
+        let config = test_config(PythonImplementation::CPython, 11, true, "3.11");
+
+        let mut buf = Vec::new();
+        config.to_writer(&mut buf).unwrap();
+        let parsed = InterpreterConfig::from_reader(buf.as_slice()).unwrap();
+
+        assert_eq!(parsed.version, config.version);
+        assert_eq!(parsed.libdir, config.libdir);
+        assert_eq!(parsed.shared, config.shared);
+        assert_eq!(parsed.ld_version, config.ld_version);
+        assert_eq!(parsed.base_prefix, config.base_prefix);
+        assert_eq!(parsed.executable, config.executable);
+        assert_eq!(parsed.calcsize_pointer, config.calcsize_pointer);
+    }
+
+    #[test]
+    fn round_trips_other_implementation_without_double_wrapping() {
+        let config = test_config(
+            PythonImplementation::Other("Jython".to_string()),
+            8,
+            true,
+            "3.8",
+        );
+
+        let mut buf = Vec::new();
+        config.to_writer(&mut buf).unwrap();
+        let parsed = InterpreterConfig::from_reader(buf.as_slice()).unwrap();
+
+        assert_eq!(
+            parsed.version.implementation,
+            PythonImplementation::Other("Jython".to_string())
+        );
+    }
+
+    #[test]
+    fn round_trips_config_with_no_libdir() {
+        let mut config = test_config(PythonImplementation::CPython, 11, false, "3.11");
+        config.libdir = None;
+
+        let mut buf = Vec::new();
+        config.to_writer(&mut buf).unwrap();
+        let parsed = InterpreterConfig::from_reader(buf.as_slice()).unwrap();
+
+        assert_eq!(parsed.libdir, None);
+    }
+
+    #[test]
+    fn from_reader_errors_on_missing_key() {
+        let result = InterpreterConfig::from_reader("version_major=3\n".as_bytes());
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod libpython_link_name_tests {
+    use super::*;
+
+    #[test]
+    fn unix_shared_uses_ld_version() {
+        let config = test_config(PythonImplementation::CPython, 11, true, "3.11");
+        assert_eq!(config.libpython_link_name_for("linux"), "python3.11");
+    }
+
+    #[test]
+    fn windows_uses_major_minor_no_dot() {
+        let config = test_config(PythonImplementation::CPython, 11, true, "3.11");
+        assert_eq!(config.libpython_link_name_for("windows"), "python311");
+    }
+
+    #[test]
+    fn pypy_uses_pypy3_c_regardless_of_target_os() {
+        let config = test_config(PythonImplementation::PyPy, 9, true, "3.9");
+        assert_eq!(config.libpython_link_name_for("linux"), "pypy3-c");
+        assert_eq!(config.libpython_link_name_for("windows"), "pypy3-c");
+    }
+
+    #[test]
+    fn shared_emits_search_dir_and_link_lib() {
+        let config = test_config(PythonImplementation::CPython, 11, true, "3.11");
+        assert_eq!(
+            config.cargo_link_directives(),
+            Some((Some("/usr/lib".to_string()), "python3.11".to_string()))
+        );
+    }
+
+    #[test]
+    fn non_shared_emits_nothing() {
+        let config = test_config(PythonImplementation::CPython, 11, false, "3.11");
+        assert_eq!(config.cargo_link_directives(), None);
+    }
+}
+
+/// The newest Python 3 minor version recognized for abi3 (limited API)
+/// linking.
+pub const ABI3_MAX_MINOR: u8 = 13;
+
+/// A request to link against CPython's abi3 (limited/stable ABI) targeting a
+/// minimum minor version, instead of the specific interpreter that was
+/// probed.
+///
+/// See [`InterpreterConfig::abi3_link_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Abi3 {
+    pub min_minor: u8,
+}
+
+#[cfg(test)]
+mod abi3_tests {
+    use super::*;
+
+    #[test]
+    fn ok_for_cpython_at_or_above_min_minor() {
+        let config = test_config(PythonImplementation::CPython, 10, true, "3.10");
+        assert_eq!(
+            config.abi3_link_name(Abi3 { min_minor: 8 }).unwrap(),
+            "python3"
+        );
+    }
+
+    #[test]
+    fn errors_for_non_cpython() {
+        let config = test_config(PythonImplementation::PyPy, 10, true, "3.10");
+        assert!(config.abi3_link_name(Abi3 { min_minor: 8 }).is_err());
+    }
+
+    #[test]
+    fn errors_when_min_minor_exceeds_abi3_max_minor() {
+        let config = test_config(PythonImplementation::CPython, 10, true, "3.10");
+        assert!(config
+            .abi3_link_name(Abi3 {
+                min_minor: ABI3_MAX_MINOR + 1
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn errors_when_interpreter_older_than_requested_min_minor() {
+        let config = test_config(PythonImplementation::CPython, 7, true, "3.7");
+        assert!(config.abi3_link_name(Abi3 { min_minor: 8 }).is_err());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PythonImplementation {
     CPython,
     PyPy,
+    GraalPy,
+    /// An implementation not otherwise recognized, identified by whatever
+    /// `platform.python_implementation()` returned.
+    Other(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PythonVersion {
     pub major: u8,
-    // minor == None means any minor version will do
     pub minor: u8,
     pub implementation: PythonImplementation,
 }
 
 impl fmt::Display for PythonVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?} {}.{}", self.implementation, self.major, self.minor)
+        write!(f, "{} {}.{}", self.implementation, self.major, self.minor)
+    }
+}
+
+impl PartialOrd for PythonVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PythonVersion {
+    /// Compares `(major, minor)` lexicographically. `implementation` does
+    /// not participate in the ordering.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor).cmp(&(other.major, other.minor))
+    }
+}
+
+/// A request for an interpreter version, used with
+/// [`find_interpreter_for_version`].
+///
+/// `min`/`max` are bare `(major, minor)` pairs rather than [`PythonVersion`]s,
+/// since a version bound has no implementation of its own — matching a
+/// specific implementation is controlled separately by the `implementation`
+/// field.
+///
+/// For example, `VersionRequest { min: (3, 8), max: None, implementation: Some(PythonImplementation::CPython) }`
+/// asks for the newest available interpreter that is at least CPython 3.8.
+#[derive(Debug, Clone)]
+pub struct VersionRequest {
+    /// The oldest acceptable `(major, minor)` version, inclusive.
+    pub min: (u8, u8),
+    /// The oldest unacceptable `(major, minor)` version, exclusive, if any.
+    pub max: Option<(u8, u8)>,
+    /// Restrict matches to a specific implementation, if any.
+    pub implementation: Option<PythonImplementation>,
+}
+
+/// Find the newest interpreter yielded by [`find_interpreters`] that
+/// satisfies `req`.
+pub fn find_interpreter_for_version(req: &VersionRequest) -> Option<InterpreterConfig> {
+    select_newest_matching(find_interpreters(), req)
+}
+
+/// The core of [`find_interpreter_for_version`], taking the candidate
+/// interpreters as a parameter so it can be tested without spawning real
+/// interpreters.
+fn select_newest_matching(
+    configs: impl Iterator<Item = InterpreterConfig>,
+    req: &VersionRequest,
+) -> Option<InterpreterConfig> {
+    let mut matches: Vec<InterpreterConfig> = configs
+        .filter(|config| (config.version.major, config.version.minor) >= req.min)
+        .filter(|config| match req.max {
+            Some(max) => (config.version.major, config.version.minor) < max,
+            None => true,
+        })
+        .filter(|config| match &req.implementation {
+            Some(implementation) => &config.version.implementation == implementation,
+            None => true,
+        })
+        .collect();
+    matches.sort_by(|a, b| b.version.cmp(&a.version));
+    matches.into_iter().next()
+}
+
+#[cfg(test)]
+mod version_tests {
+    use super::*;
+
+    fn version(major: u8, minor: u8, implementation: PythonImplementation) -> PythonVersion {
+        PythonVersion {
+            major,
+            minor,
+            implementation,
+        }
+    }
+
+    #[test]
+    fn ord_compares_major_then_minor() {
+        assert!(version(3, 8, PythonImplementation::CPython) < version(3, 9, PythonImplementation::CPython));
+        assert!(version(3, 9, PythonImplementation::CPython) < version(4, 0, PythonImplementation::CPython));
+    }
+
+    #[test]
+    fn ord_ignores_implementation() {
+        assert_eq!(
+            version(3, 8, PythonImplementation::CPython).cmp(&version(3, 8, PythonImplementation::PyPy)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn select_newest_matching_respects_min_and_max() {
+        let configs = vec![
+            test_config(PythonImplementation::CPython, 7, true, "3.7"),
+            test_config(PythonImplementation::CPython, 9, true, "3.9"),
+            test_config(PythonImplementation::CPython, 12, true, "3.12"),
+        ];
+        let req = VersionRequest {
+            min: (3, 8),
+            max: Some((3, 12)),
+            implementation: None,
+        };
+        let found = select_newest_matching(configs.into_iter(), &req).unwrap();
+        assert_eq!(found.version.minor, 9);
+    }
+
+    #[test]
+    fn select_newest_matching_filters_by_implementation() {
+        let configs = vec![
+            test_config(PythonImplementation::PyPy, 10, true, "3.10"),
+            test_config(PythonImplementation::CPython, 9, true, "3.9"),
+        ];
+        let req = VersionRequest {
+            min: (3, 8),
+            max: None,
+            implementation: Some(PythonImplementation::CPython),
+        };
+        let found = select_newest_matching(configs.into_iter(), &req).unwrap();
+        assert_eq!(found.version.implementation, PythonImplementation::CPython);
+        assert_eq!(found.version.minor, 9);
+    }
+
+    #[test]
+    fn select_newest_matching_none_when_nothing_satisfies() {
+        let configs = vec![test_config(PythonImplementation::CPython, 6, true, "3.6")];
+        let req = VersionRequest {
+            min: (3, 8),
+            max: None,
+            implementation: None,
+        };
+        assert!(select_newest_matching(configs.into_iter(), &req).is_none());
+    }
+}
+
+impl fmt::Display for PythonImplementation {
+    /// The inverse of `FromStr`: round-trips through `s.parse()` back to the
+    /// same variant, including `Other`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PythonImplementation::CPython => write!(f, "CPython"),
+            PythonImplementation::PyPy => write!(f, "PyPy"),
+            PythonImplementation::GraalPy => write!(f, "GraalPy"),
+            PythonImplementation::Other(name) => write!(f, "{}", name),
+        }
     }
 }
 
@@ -113,7 +804,8 @@ impl FromStr for PythonImplementation {
         match s {
             "CPython" => Ok(PythonImplementation::CPython),
             "PyPy" => Ok(PythonImplementation::PyPy),
-            _ => Err(format!("Invalid interpreter: {}", s).into()),
+            "GraalVM" | "GraalPy" => Ok(PythonImplementation::GraalPy),
+            other => Ok(PythonImplementation::Other(other.to_string())),
         }
     }
 }
@@ -150,18 +842,139 @@ fn run_python_script(interpreter: &Path, script: &str) -> Result<String> {
     }
 }
 
-/// Search for python interpreters and yield them in order.
+/// The default range of CPython minor versions probed by [`find_interpreters`]
+/// when searching for versioned interpreter names such as `python3.11`.
+const DEFAULT_MINOR_VERSION_RANGE: std::ops::RangeInclusive<u8> = 6..=13;
+
+/// Search for python interpreters and yield them in order, newest version
+/// first.
 ///
-/// The following locations are checked in the order listed:
+/// In addition to the literal names `python`/`python3`/`pypy`/`pypy3`, this
+/// probes versioned names like `python3.11` across
+/// [`DEFAULT_MINOR_VERSION_RANGE`] and walks each directory on `PATH` for
+/// executables that look like a Python interpreter, so that systems which
+/// only have e.g. `python3.11` or `pypy3` on `PATH` are still found.
+/// Interpreters are deduplicated by their resolved `executable` path, since
+/// multiple names may point at the same binary.
 ///
-/// 1. `python`
-/// 2. `python3`
+/// To probe a different range of minor versions, use
+/// [`find_interpreters_in_range`] instead.
 pub fn find_interpreters() -> impl Iterator<Item = InterpreterConfig> {
-    ["python", "python3"]
-        .iter()
-        .filter_map(|interpreter| {
-            get_config_from_interpreter(Path::new(interpreter)).ok()
+    find_interpreters_in_range(DEFAULT_MINOR_VERSION_RANGE)
+}
+
+/// Like [`find_interpreters`], but probes versioned names (`python3.X`)
+/// across the given minor-version range instead of
+/// [`DEFAULT_MINOR_VERSION_RANGE`].
+pub fn find_interpreters_in_range(
+    minor_version_range: std::ops::RangeInclusive<u8>,
+) -> impl Iterator<Item = InterpreterConfig> {
+    let mut names = base_interpreter_names(minor_version_range);
+
+    for name in candidate_names_on_path() {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let configs = names
+        .into_iter()
+        .filter_map(|name| get_config_from_interpreter(Path::new(&name)).ok());
+    sorted_unique_by_executable(configs).into_iter()
+}
+
+/// Build the literal and versioned interpreter names to probe: `python`,
+/// `python3`, `python3.X` for each minor version in `minor_version_range`
+/// (newest first), then `pypy3`/`pypy`.
+fn base_interpreter_names(minor_version_range: std::ops::RangeInclusive<u8>) -> Vec<String> {
+    let mut names: Vec<String> = vec!["python".into(), "python3".into()];
+    for minor in minor_version_range.rev() {
+        names.push(format!("python3.{}", minor));
+    }
+    names.push("pypy3".into());
+    names.push("pypy".into());
+    names
+}
+
+/// Deduplicate `configs` by their resolved `executable` path (since multiple
+/// names may point at the same binary) and sort newest version first.
+fn sorted_unique_by_executable(
+    configs: impl Iterator<Item = InterpreterConfig>,
+) -> Vec<InterpreterConfig> {
+    let mut seen = std::collections::HashSet::new();
+    let mut configs: Vec<InterpreterConfig> = configs
+        .filter(|config| {
+            let resolved = std::fs::canonicalize(&config.executable)
+                .unwrap_or_else(|_| config.executable.clone());
+            seen.insert(resolved)
         })
+        .collect();
+
+    configs.sort_by(|a, b| b.version.cmp(&a.version));
+    configs
+}
+
+#[cfg(test)]
+mod discovery_tests {
+    use super::*;
+
+    #[test]
+    fn base_interpreter_names_orders_versions_newest_first() {
+        let names = base_interpreter_names(8..=10);
+        assert_eq!(
+            names,
+            vec!["python", "python3", "python3.10", "python3.9", "python3.8", "pypy3", "pypy"]
+        );
+    }
+
+    #[test]
+    fn sorted_unique_by_executable_dedups_by_executable_path() {
+        let mut same_binary = test_config(PythonImplementation::CPython, 11, true, "3.11");
+        same_binary.executable = PathBuf::from("/usr/bin/python3.11");
+        let mut alias = test_config(PythonImplementation::CPython, 11, true, "3.11");
+        alias.executable = PathBuf::from("/usr/bin/python3.11");
+        let mut other = test_config(PythonImplementation::CPython, 9, true, "3.9");
+        other.executable = PathBuf::from("/usr/bin/python3.9");
+
+        let configs = sorted_unique_by_executable(vec![same_binary, alias, other].into_iter());
+        assert_eq!(configs.len(), 2);
+    }
+
+    #[test]
+    fn sorted_unique_by_executable_sorts_newest_first() {
+        let mut old = test_config(PythonImplementation::CPython, 9, true, "3.9");
+        old.executable = PathBuf::from("/usr/bin/python3.9");
+        let mut new = test_config(PythonImplementation::CPython, 12, true, "3.12");
+        new.executable = PathBuf::from("/usr/bin/python3.12");
+
+        let configs = sorted_unique_by_executable(vec![old, new].into_iter());
+        assert_eq!(configs[0].version.minor, 12);
+        assert_eq!(configs[1].version.minor, 9);
+    }
+}
+
+/// Walk each directory on `PATH` looking for executables that look like a
+/// Python interpreter (`python*`, `pypy*`), for use by [`find_interpreters`].
+fn candidate_names_on_path() -> Vec<String> {
+    let mut names = Vec::new();
+    let path = match std::env::var_os("PATH") {
+        Some(path) => path,
+        None => return names,
+    };
+    for dir in std::env::split_paths(&path) {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+            if (name.starts_with("python") || name.starts_with("pypy")) && !name.ends_with("-config") {
+                names.push(name.into_owned());
+            }
+        }
+    }
+    names
 }
 
 /// Return the first interpreter matching the given criterion.